@@ -0,0 +1,44 @@
+// @generated by `cargo xtask codegen`. Do not edit by hand.
+
+pub struct Mappings;
+
+impl Mappings {
+    #[must_use]
+    pub fn schema_field(&self, cli_type: &str) -> Option<&'static str> {
+        match cli_type {
+            "string" | "str" => Some("string"),
+            "text" => Some("text"),
+            "integer" | "int" => Some("integer"),
+            "bigint" => Some("big_integer"),
+            "float" => Some("float"),
+            "double" => Some("double"),
+            "decimal" => Some("decimal"),
+            "bool" | "boolean" => Some("boolean"),
+            "date" => Some("date"),
+            "ts" | "timestamp" => Some("timestamp"),
+            "uuid" => Some("uuid"),
+            "json" | "jsonb" => Some("json"),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn schema_fields(&self) -> Vec<&'static str> {
+        vec![
+            "string",
+            "text",
+            "integer",
+            "bigint",
+            "float",
+            "double",
+            "decimal",
+            "bool",
+            "date",
+            "ts",
+            "uuid",
+            "json",
+        ]
+    }
+}
+
+pub static MAPPINGS: Mappings = Mappings;
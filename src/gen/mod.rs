@@ -0,0 +1,20 @@
+pub mod model;
+
+mod mappings;
+pub use mappings::MAPPINGS;
+
+/// Information about the app being generated into, threaded through every
+/// generator so templates can emit the right crate name and paths.
+pub struct AppInfo {
+    pub app_name: String,
+}
+
+/// Flatten the messages returned by one or more `rrgen` template renders
+/// into a single string to print back to the user.
+pub fn collect_messages(results: Vec<rrgen::GenResult>) -> String {
+    results
+        .into_iter()
+        .filter_map(|r| r.message)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -9,6 +9,8 @@ use crate::{errors::Error, Result};
 
 const MODEL_T: &str = include_str!("templates/model.t");
 const MODEL_TEST_T: &str = include_str!("templates/model_test.t");
+const RELATIONS_T: &str = include_str!("templates/relations.t");
+const JOIN_TABLE_T: &str = include_str!("templates/join_table.t");
 
 use super::{collect_messages, AppInfo, MAPPINGS};
 
@@ -17,6 +19,158 @@ use super::{collect_messages, AppInfo, MAPPINGS};
 /// generated by the Loco app and should be given
 pub const IGNORE_FIELDS: &[&str] = &["created_at", "updated_at", "create_at", "update_at"];
 
+/// A single migration column, after the `ftype` modifier grammar has been
+/// parsed out of the raw CLI token (e.g. `price:decimal(10,2)`,
+/// `email:string!:uniq`, `bio:text?`, `slug:string:index`).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Column {
+    pub name: String,
+    pub schema_type: String,
+    pub nullable: bool,
+    pub unique: bool,
+    pub indexed: bool,
+    pub default: Option<String>,
+    pub precision: Option<(u32, u32)>,
+}
+
+/// The modifiers lifted off a raw `ftype` token, with `base` still pointing
+/// at the plain schema type name (e.g. `decimal`, `string`, `text`).
+struct FieldModifiers<'a> {
+    base: &'a str,
+    nullable: bool,
+    unique: bool,
+    indexed: bool,
+    default: Option<String>,
+    precision: Option<(u32, u32)>,
+    on_delete: Option<String>,
+    on_update: Option<String>,
+}
+
+/// The `on_delete`/`on_update` action a foreign key constraint can be given,
+/// carried as a `:cascade`/`:restrict`/`:set_null` modifier on a `belongs_to`
+/// (or legacy `references`) field, e.g. `author:belongs_to:cascade`. Prefix
+/// with `update_` to target `on_update` instead of `on_delete`.
+fn parse_fk_action(modifier: &str) -> Option<(&'static str, String)> {
+    let (slot, action) = modifier
+        .strip_prefix("update_")
+        .map_or(("on_delete", modifier), |rest| ("on_update", rest));
+    match action {
+        "cascade" => Some((slot, "Cascade".to_string())),
+        "restrict" => Some((slot, "Restrict".to_string())),
+        "set_null" => Some((slot, "SetNull".to_string())),
+        _ => None,
+    }
+}
+
+/// Parse the suffix grammar on an `ftype` token: `?` marks the column
+/// nullable, `!` marks it not-null (the default, so it's only ever
+/// informational), `:uniq`/`:index` request a unique/secondary index,
+/// `:cascade`/`:restrict`/`:set_null` (optionally `update_`-prefixed) set a
+/// foreign-key action, `=` sets a default value, and `(p,s)` sets decimal
+/// precision/scale.
+fn parse_ftype(ftype: &str) -> Result<FieldModifiers<'_>> {
+    let mut parts = ftype.split(':');
+    let head = parts.next().unwrap_or(ftype);
+
+    let (mut base, default) = match head.find('=') {
+        Some(idx) => (&head[..idx], Some(head[idx + 1..].to_string())),
+        None => (head, None),
+    };
+
+    let mut unique = false;
+    let mut indexed = false;
+    let mut on_delete = None;
+    let mut on_update = None;
+    for modifier in parts {
+        match modifier {
+            "uniq" => unique = true,
+            "index" => indexed = true,
+            other => match parse_fk_action(other) {
+                Some(("on_delete", action)) => on_delete = Some(action),
+                Some((_, action)) => on_update = Some(action),
+                None => {
+                    return Err(Error::Message(format!(
+                        "unknown field modifier `{other}` in type `{ftype}`. try any of: uniq, index, cascade, restrict, set_null"
+                    )))
+                }
+            },
+        }
+    }
+
+    let mut nullable = false;
+    if let Some(stripped) = base.strip_suffix('?') {
+        nullable = true;
+        base = stripped;
+    } else if let Some(stripped) = base.strip_suffix('!') {
+        base = stripped;
+    }
+
+    let mut precision = None;
+    if let Some(open) = base.find('(') {
+        let args = base[open + 1..].strip_suffix(')').ok_or_else(|| {
+            Error::Message(format!("unbalanced parens in type `{ftype}`"))
+        })?;
+        let (p, s) = args.split_once(',').ok_or_else(|| {
+            Error::Message(format!(
+                "expected `(precision,scale)` in type `{ftype}`, got `({args})`"
+            ))
+        })?;
+        let precision_val: u32 = p.trim().parse().map_err(|_| {
+            Error::Message(format!("invalid precision in type `{ftype}`"))
+        })?;
+        let scale_val: u32 = s.trim().parse().map_err(|_| {
+            Error::Message(format!("invalid scale in type `{ftype}`"))
+        })?;
+        precision = Some((precision_val, scale_val));
+        base = &base[..open];
+    }
+
+    if unique && nullable {
+        tracing::warn!(
+            ftype,
+            "a unique column is also marked nullable; most databases allow multiple NULLs through a unique index"
+        );
+    }
+
+    Ok(FieldModifiers {
+        base,
+        nullable,
+        unique,
+        indexed,
+        default,
+        precision,
+        on_delete,
+        on_update,
+    })
+}
+
+/// The association kind a field can declare, beyond a plain scalar column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssociationKind {
+    BelongsTo,
+    HasMany,
+    HasOne,
+    ManyToMany,
+}
+
+/// A relation between this model and another one, rendered into the
+/// generated entity's `Relation` enum and `Related` impls. `belongs_to`
+/// (and the legacy `references` alias) owns the foreign-key column and may
+/// carry `on_delete`/`on_update` actions; `has_many`/`has_one` are the
+/// inverse side and own no column; `many_to_many` additionally produces a
+/// join-table migration.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Association {
+    pub kind: AssociationKind,
+    /// the other model's name, singular, snake_case
+    pub name: String,
+    pub fkey: Option<String>,
+    pub on_delete: Option<String>,
+    pub on_update: Option<String>,
+    pub join_table: Option<String>,
+}
+
 pub fn generate(
     rrgen: &RRgen,
     name: &str,
@@ -30,6 +184,7 @@ pub fn generate(
 
     let mut columns = Vec::new();
     let mut references = Vec::new();
+    let mut associations = Vec::new();
     for (fname, ftype) in fields {
         if IGNORE_FIELDS.contains(&fname.as_str()) {
             tracing::warn!(
@@ -38,27 +193,107 @@ pub fn generate(
             );
             continue;
         }
-        if ftype == "references" {
-            let fkey = format!("{fname}_id");
-            columns.push((fkey.clone(), "integer"));
-            // user, user_id
-            references.push((fname, fkey));
-        } else {
-            let schema_type = MAPPINGS.schema_field(ftype.as_str()).ok_or_else(|| {
-                Error::Message(format!(
-                    "type: {} not found. try any of: {:?}",
-                    ftype,
-                    MAPPINGS.schema_fields()
-                ))
-            })?;
-            columns.push((fname.to_string(), schema_type.as_str()));
+        let modifiers = parse_ftype(ftype)?;
+        match modifiers.base {
+            // `references` is kept as a back-compat alias for `belongs_to`.
+            "references" | "belongs_to" => {
+                let fkey = format!("{fname}_id");
+                columns.push(Column {
+                    name: fkey.clone(),
+                    schema_type: "integer".to_string(),
+                    nullable: modifiers.nullable,
+                    unique: modifiers.unique,
+                    indexed: modifiers.indexed,
+                    default: modifiers.default,
+                    precision: None,
+                });
+                // user, user_id
+                references.push((fname.to_string(), fkey.clone()));
+                associations.push(Association {
+                    kind: AssociationKind::BelongsTo,
+                    name: fname.to_string(),
+                    fkey: Some(fkey),
+                    on_delete: modifiers.on_delete,
+                    on_update: modifiers.on_update,
+                    join_table: None,
+                });
+            }
+            "has_many" => associations.push(Association {
+                kind: AssociationKind::HasMany,
+                name: fname.to_string(),
+                fkey: None,
+                on_delete: None,
+                on_update: None,
+                join_table: None,
+            }),
+            "has_one" => associations.push(Association {
+                kind: AssociationKind::HasOne,
+                name: fname.to_string(),
+                fkey: None,
+                on_delete: None,
+                on_update: None,
+                join_table: None,
+            }),
+            "many_to_many" => associations.push(Association {
+                kind: AssociationKind::ManyToMany,
+                name: fname.to_string(),
+                fkey: None,
+                on_delete: None,
+                on_update: None,
+                join_table: Some(format!("{}_{}", name, fname)),
+            }),
+            base => {
+                let schema_type = MAPPINGS.schema_field(base).ok_or_else(|| {
+                    Error::Message(format!(
+                        "type: {} not found. try any of: {:?}",
+                        base,
+                        MAPPINGS.schema_fields()
+                    ))
+                })?;
+                columns.push(Column {
+                    name: fname.to_string(),
+                    schema_type: schema_type.to_string(),
+                    nullable: modifiers.nullable,
+                    unique: modifiers.unique,
+                    indexed: modifiers.indexed,
+                    default: modifiers.default,
+                    precision: modifiers.precision,
+                });
+            }
         }
     }
 
-    let vars = json!({"name": name, "ts": ts, "pkg_name": pkg_name, "is_link": is_link, "columns": columns, "references": references});
+    let vars = json!({"name": name, "ts": ts, "pkg_name": pkg_name, "is_link": is_link, "columns": columns, "references": references, "associations": associations});
     let res1 = rrgen.generate(MODEL_T, &vars)?;
     let res2 = rrgen.generate(MODEL_TEST_T, &vars)?;
 
+    let mut gen_results = vec![res1, res2];
+
+    if !associations.is_empty() {
+        gen_results.push(rrgen.generate(RELATIONS_T, &vars)?);
+    }
+
+    for (i, assoc) in associations
+        .iter()
+        .filter(|a| a.join_table.is_some())
+        .enumerate()
+    {
+        let join_table = assoc.join_table.as_ref().expect("join_table is some");
+        let join_vars = json!({
+            "name": name,
+            // offset each join-table migration by a second so it is
+            // timestamped after the model's own migration and after any
+            // earlier sibling `many_to_many` join table
+            "ts": ts + chrono::Duration::seconds(i as i64 + 1),
+            "pkg_name": pkg_name,
+            "join_table": join_table,
+            "left_fkey": format!("{name}_id"),
+            "right_fkey": format!("{}_id", assoc.name),
+            "right_name": assoc.name,
+        });
+        gen_results.push(rrgen.generate(JOIN_TABLE_T, &join_vars)?);
+    }
+
     if !migration_only {
         let cwd = current_dir()?;
         let env_map: HashMap<_, _> = std::env::vars().collect();
@@ -85,7 +320,7 @@ pub fn generate(
             })?;
     }
 
-    let messages = collect_messages(vec![res1, res2]);
+    let messages = collect_messages(gen_results);
     Ok(messages)
 }
 
@@ -93,6 +328,8 @@ pub fn generate(
 mod tests {
     use std::env;
 
+    use super::parse_ftype;
+
     #[test]
     fn test_can_generate_app() {
         let curdir = env::current_dir().unwrap();
@@ -119,4 +356,54 @@ mod tests {
         env::set_current_dir(curdir).unwrap();
         panic!();
     }
+
+    #[test]
+    fn test_parse_ftype_modifiers() {
+        let m = parse_ftype("decimal(10,2)").unwrap();
+        assert_eq!(m.base, "decimal");
+        assert_eq!(m.precision, Some((10, 2)));
+        assert!(!m.nullable);
+
+        let m = parse_ftype("string!:uniq").unwrap();
+        assert_eq!(m.base, "string");
+        assert!(m.unique);
+        assert!(!m.nullable);
+
+        let m = parse_ftype("text?").unwrap();
+        assert_eq!(m.base, "text");
+        assert!(m.nullable);
+
+        let m = parse_ftype("string:index").unwrap();
+        assert_eq!(m.base, "string");
+        assert!(m.indexed);
+
+        let m = parse_ftype("string=active").unwrap();
+        assert_eq!(m.base, "string");
+        assert_eq!(m.default, Some("active".to_string()));
+
+        let m = parse_ftype("string=admin:uniq").unwrap();
+        assert_eq!(m.base, "string");
+        assert_eq!(m.default, Some("admin".to_string()));
+        assert!(m.unique);
+    }
+
+    #[test]
+    fn test_parse_ftype_rejects_unknown_modifier() {
+        assert!(parse_ftype("string:bogus").is_err());
+    }
+
+    #[test]
+    fn test_parse_ftype_association_fk_actions() {
+        let m = parse_ftype("belongs_to:cascade").unwrap();
+        assert_eq!(m.base, "belongs_to");
+        assert_eq!(m.on_delete, Some("Cascade".to_string()));
+        assert_eq!(m.on_update, None);
+
+        let m = parse_ftype("belongs_to:update_restrict").unwrap();
+        assert_eq!(m.on_update, Some("Restrict".to_string()));
+        assert_eq!(m.on_delete, None);
+
+        let m = parse_ftype("belongs_to:set_null").unwrap();
+        assert_eq!(m.on_delete, Some("SetNull".to_string()));
+    }
 }
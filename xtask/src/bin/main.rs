@@ -24,10 +24,24 @@ enum Commands {
     },
     /// Bump loco version in all dependencies places
     BumpVersion {
-        #[arg(name = "VERSION")]
-        new_version: Version,
+        /// The exact version to bump to. Conflicts with `--level`; `--pre`
+        /// is applied on top of it
+        #[arg(name = "VERSION", conflicts_with = "level")]
+        new_version: Option<Version>,
+        /// Compute the next version by bumping this part of the current one
+        #[arg(long)]
+        level: Option<xtask::bump_version::Level>,
+        /// Append or increment a prerelease channel, e.g. `1.2.0-rc.1`
+        #[arg(long)]
+        pre: Option<xtask::bump_version::PreChannel>,
         #[arg(short, long, action = SetFalse)]
         exclude_starters: bool,
+        /// Create an annotated `v<version>` git tag after a confirmed bump
+        #[arg(long, action = SetTrue)]
+        tag: bool,
+        /// Allow tagging even when the working tree is dirty
+        #[arg(long, action = SetTrue)]
+        force: bool,
     },
     Fuzzy {
         #[arg(short, long, value_parser = clap::value_parser!(u64))]
@@ -35,6 +49,28 @@ enum Commands {
         #[command(subcommand)]
         command: FuzzyCommands,
     },
+    /// Package the starter templates into release-ready archives
+    Dist {
+        /// Where to write the produced `.tar.gz` archives
+        #[arg(long, default_value = "target/dist")]
+        out_dir: std::path::PathBuf,
+        /// Strip `target/release/loco-tool` (if present) after the starter
+        /// archives are written; the binary is not added to any archive
+        #[arg(long, action = SetTrue)]
+        strip: bool,
+    },
+    /// Publish the workspace crates in dependency order
+    Publish {
+        /// Print the publish plan without actually publishing anything
+        #[arg(long, action = SetTrue)]
+        dry_run: bool,
+    },
+    /// Regenerate the field-type `MAPPINGS` and its docs from one source of truth
+    Codegen {
+        /// Check that the generated files are up to date instead of writing them
+        #[arg(long, action = SetTrue)]
+        verify: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -60,7 +96,11 @@ fn main() -> eyre::Result<()> {
         }
         Commands::BumpVersion {
             new_version,
+            level,
+            pre,
             exclude_starters,
+            tag,
+            force,
         } => {
             let meta = MetadataCommand::new()
                 .manifest_path("./Cargo.toml")
@@ -68,16 +108,41 @@ fn main() -> eyre::Result<()> {
                 .exec()
                 .unwrap();
             let root: &Package = meta.root_package().unwrap();
+
+            let mut version = match new_version {
+                Some(new_version) => new_version,
+                None => {
+                    let level = level
+                        .ok_or_else(|| eyre::eyre!("either VERSION or --level must be given"))?;
+                    xtask::bump_version::next_version(&root.version, level)
+                }
+            };
+            if let Some(pre) = pre {
+                xtask::bump_version::apply_prerelease(&mut version, pre)?;
+            }
+
             if xtask::prompt::confirmation(&format!(
                 "upgrading loco version from {} to {}",
-                root.version, new_version,
+                root.version, version,
             ))? {
-                xtask::bump_version::BumpVersion {
+                let bump = xtask::bump_version::BumpVersion {
                     base_dir: project_dir,
-                    version: new_version,
+                    version,
                     bump_starters: exclude_starters,
+                };
+
+                // check cleanliness before `run()` mutates the tree, since
+                // the bump itself would otherwise always make it dirty
+                if tag && !force && !bump.is_clean()? {
+                    eyre::bail!(
+                        "working tree is dirty; commit your changes or pass --force to tag anyway"
+                    );
+                }
+
+                bump.run()?;
+                if tag {
+                    bump.commit_and_tag()?;
                 }
-                .run()?;
             }
             xtask::CmdExit::ok()
         }
@@ -99,7 +164,9 @@ fn main() -> eyre::Result<()> {
 
             let result = runner.run();
 
-            if temp_dir.exists() {
+            // on failure the temp dir holds the (possibly shrunk) minimal
+            // reproducer, so leave it for inspection instead of deleting it
+            if result.is_ok() && temp_dir.exists() {
                 std::fs::remove_dir_all(temp_dir).expect("remove dir");
             }
 
@@ -111,6 +178,42 @@ fn main() -> eyre::Result<()> {
                 xtask::CmdExit::ok()
             }
         }
+        Commands::Dist { out_dir, strip } => {
+            let dist = xtask::dist::Dist {
+                base_dir: project_dir,
+                out_dir,
+                strip,
+            };
+            match dist.run() {
+                Ok(archives) => {
+                    for archive in archives {
+                        println!("{}", archive.display());
+                    }
+                    xtask::CmdExit::ok()
+                }
+                Err(err) => xtask::CmdExit::error_with_message(&format!("dist failed: {err}")),
+            }
+        }
+        Commands::Publish { dry_run } => {
+            let publish = xtask::publish::Publish {
+                base_dir: project_dir,
+                dry_run,
+            };
+            match publish.run() {
+                Ok(()) => xtask::CmdExit::ok(),
+                Err(err) => xtask::CmdExit::error_with_message(&format!("publish failed: {err}")),
+            }
+        }
+        Commands::Codegen { verify } => {
+            let codegen = xtask::codegen::Codegen {
+                base_dir: project_dir,
+                verify,
+            };
+            match codegen.run() {
+                Ok(()) => xtask::CmdExit::ok(),
+                Err(err) => xtask::CmdExit::error_with_message(&format!("codegen failed: {err}")),
+            }
+        }
     };
 
     res.exit();
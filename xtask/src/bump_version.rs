@@ -0,0 +1,154 @@
+use std::path::PathBuf;
+
+use cargo_metadata::semver::{BuildMetadata, Prerelease, Version};
+
+/// A semantic-versioning bump level, as in `--level major|minor|patch`.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+#[clap(rename_all = "lower")]
+pub enum Level {
+    Major,
+    Minor,
+    Patch,
+}
+
+/// A prerelease channel, as in `--pre alpha|beta|rc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[clap(rename_all = "lower")]
+pub enum PreChannel {
+    Alpha,
+    Beta,
+    Rc,
+}
+
+impl PreChannel {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Alpha => "alpha",
+            Self::Beta => "beta",
+            Self::Rc => "rc",
+        }
+    }
+}
+
+/// Bump `current` by `level`, dropping any existing prerelease/build
+/// metadata (a fresh `--level` bump always starts a new release line).
+#[must_use]
+pub fn next_version(current: &Version, level: Level) -> Version {
+    let mut next = current.clone();
+    match level {
+        Level::Major => {
+            next.major += 1;
+            next.minor = 0;
+            next.patch = 0;
+        }
+        Level::Minor => {
+            next.minor += 1;
+            next.patch = 0;
+        }
+        Level::Patch => next.patch += 1,
+    }
+    next.pre = Prerelease::EMPTY;
+    next.build = BuildMetadata::EMPTY;
+    next
+}
+
+/// Apply (or increment) a `--pre` channel on `version`, e.g.
+/// `1.2.0` -> `1.2.0-rc.1`, and `1.2.0-rc.1` -> `1.2.0-rc.2`.
+pub fn apply_prerelease(version: &mut Version, channel: PreChannel) -> eyre::Result<()> {
+    let next_n = match version.pre.as_str().split_once('.') {
+        Some((existing_channel, n)) if existing_channel == channel.as_str() => {
+            n.parse::<u32>().unwrap_or(0) + 1
+        }
+        _ => 1,
+    };
+    version.pre = Prerelease::new(&format!("{}.{next_n}", channel.as_str()))?;
+    Ok(())
+}
+
+/// Rewrites the root `Cargo.toml` (and, unless excluded, each
+/// `starters/*/Cargo.toml`), setting both `[package] version` and the
+/// `loco-rs` dependency's version to `self.version`. Other workspace
+/// members' manifests are not touched.
+pub struct BumpVersion {
+    pub base_dir: PathBuf,
+    pub version: Version,
+    pub bump_starters: bool,
+}
+
+impl BumpVersion {
+    pub fn run(&self) -> eyre::Result<()> {
+        for manifest in self.manifest_paths()? {
+            self.bump_manifest(&manifest)?;
+        }
+        Ok(())
+    }
+
+    /// Whether `self.base_dir` has no uncommitted changes. Callers should
+    /// check this *before* calling [`Self::run`], since `run` itself
+    /// rewrites every `Cargo.toml` and would otherwise always report the
+    /// tree as dirty.
+    pub fn is_clean(&self) -> eyre::Result<bool> {
+        let status = duct::cmd!("git", "status", "--porcelain")
+            .dir(&self.base_dir)
+            .read()?;
+        Ok(status.trim().is_empty())
+    }
+
+    /// Commit the version bump produced by [`Self::run`] and create an
+    /// annotated `v<version>` tag on that commit, so the tag always points
+    /// at the manifests it describes rather than whatever was dirty before.
+    pub fn commit_and_tag(&self) -> eyre::Result<()> {
+        duct::cmd!(
+            "git",
+            "commit",
+            "-am",
+            format!("chore(release): bump version to {}", self.version)
+        )
+        .dir(&self.base_dir)
+        .run()?;
+
+        let tag = format!("v{}", self.version);
+        duct::cmd!("git", "tag", "-a", &tag, "-m", format!("release {tag}"))
+            .dir(&self.base_dir)
+            .run()?;
+
+        Ok(())
+    }
+
+    fn manifest_paths(&self) -> eyre::Result<Vec<PathBuf>> {
+        let mut paths = vec![self.base_dir.join("Cargo.toml")];
+
+        if self.bump_starters {
+            let starters_dir = self.base_dir.join("starters");
+            if starters_dir.is_dir() {
+                for entry in std::fs::read_dir(starters_dir)? {
+                    let manifest = entry?.path().join("Cargo.toml");
+                    if manifest.is_file() {
+                        paths.push(manifest);
+                    }
+                }
+            }
+        }
+
+        Ok(paths)
+    }
+
+    fn bump_manifest(&self, manifest: &PathBuf) -> eyre::Result<()> {
+        let mut doc = std::fs::read_to_string(manifest)?.parse::<toml_edit::DocumentMut>()?;
+
+        if let Some(pkg) = doc.get_mut("package") {
+            pkg["version"] = toml_edit::value(self.version.to_string());
+        }
+        if let Some(dep) = doc
+            .get_mut("dependencies")
+            .and_then(|deps| deps.get_mut("loco-rs"))
+        {
+            if let Some(table) = dep.as_table_like_mut() {
+                table.insert("version", toml_edit::value(self.version.to_string()));
+            }
+        }
+
+        std::fs::write(manifest, doc.to_string())?;
+        Ok(())
+    }
+}
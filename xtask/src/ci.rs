@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use duct::cmd;
+
+/// The test outcome for a single Loco resource (the library itself, or one
+/// of the starter templates).
+pub struct CiResult {
+    pub resource: String,
+    pub success: bool,
+}
+
+/// Run the test suite for the crate rooted at `project_dir`.
+pub fn run(project_dir: &Path) -> eyre::Result<CiResult> {
+    let resource = project_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "loco".to_string());
+
+    let success = cmd!("cargo", "test", "--all-features")
+        .dir(project_dir)
+        .stderr_to_stdout()
+        .run()
+        .is_ok();
+
+    Ok(CiResult { resource, success })
+}
+
+/// Run the test suite for Loco and every starter template.
+pub fn all_resources(project_dir: &Path) -> eyre::Result<Vec<CiResult>> {
+    let mut results = vec![run(project_dir)?];
+
+    let starters_dir = project_dir.join("starters");
+    if starters_dir.is_dir() {
+        for entry in std::fs::read_dir(starters_dir)? {
+            let entry = entry?;
+            if entry.path().is_dir() {
+                results.push(run(&entry.path())?);
+            }
+        }
+    }
+
+    Ok(results)
+}
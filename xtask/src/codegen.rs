@@ -0,0 +1,208 @@
+use std::path::PathBuf;
+
+/// One row of the single source-of-truth table backing both the generated
+/// `MAPPINGS` Rust source (`src/gen/mappings.rs`) and the user-facing
+/// field-type reference (`docs/field-types.md`). Add a field type here and
+/// run `cargo xtask codegen` to regenerate both.
+struct FieldType {
+    cli_type: &'static str,
+    schema_type: &'static str,
+    rust_type: &'static str,
+    aliases: &'static [&'static str],
+}
+
+const FIELD_TYPES: &[FieldType] = &[
+    FieldType {
+        cli_type: "string",
+        schema_type: "string",
+        rust_type: "String",
+        aliases: &["str"],
+    },
+    FieldType {
+        cli_type: "text",
+        schema_type: "text",
+        rust_type: "String",
+        aliases: &[],
+    },
+    FieldType {
+        cli_type: "integer",
+        schema_type: "integer",
+        rust_type: "i32",
+        aliases: &["int"],
+    },
+    FieldType {
+        cli_type: "bigint",
+        schema_type: "big_integer",
+        rust_type: "i64",
+        aliases: &[],
+    },
+    FieldType {
+        cli_type: "float",
+        schema_type: "float",
+        rust_type: "f32",
+        aliases: &[],
+    },
+    FieldType {
+        cli_type: "double",
+        schema_type: "double",
+        rust_type: "f64",
+        aliases: &[],
+    },
+    FieldType {
+        cli_type: "decimal",
+        schema_type: "decimal",
+        rust_type: "rust_decimal::Decimal",
+        aliases: &[],
+    },
+    FieldType {
+        cli_type: "bool",
+        schema_type: "boolean",
+        rust_type: "bool",
+        aliases: &["boolean"],
+    },
+    FieldType {
+        cli_type: "date",
+        schema_type: "date",
+        rust_type: "chrono::NaiveDate",
+        aliases: &[],
+    },
+    FieldType {
+        cli_type: "ts",
+        schema_type: "timestamp",
+        rust_type: "chrono::NaiveDateTime",
+        aliases: &["timestamp"],
+    },
+    FieldType {
+        cli_type: "uuid",
+        schema_type: "uuid",
+        rust_type: "uuid::Uuid",
+        aliases: &[],
+    },
+    FieldType {
+        cli_type: "json",
+        schema_type: "json",
+        rust_type: "serde_json::Value",
+        aliases: &["jsonb"],
+    },
+];
+
+/// Regenerates the `MAPPINGS` Rust source and the field-type reference doc
+/// from [`FIELD_TYPES`]. In `verify` mode it diffs the generated output
+/// against what's on disk and errors instead of writing, so CI can catch a
+/// table edit that wasn't followed by a regeneration.
+pub struct Codegen {
+    pub base_dir: PathBuf,
+    pub verify: bool,
+}
+
+impl Codegen {
+    pub fn run(&self) -> eyre::Result<()> {
+        let targets = [
+            (
+                self.base_dir.join("src/gen/mappings.rs"),
+                render_mappings_rs(),
+            ),
+            (
+                self.base_dir.join("docs/field-types.md"),
+                render_field_types_md(),
+            ),
+        ];
+
+        if self.verify {
+            return self.verify_targets(&targets);
+        }
+
+        for (path, content) in &targets {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, content)?;
+            println!("wrote {}", path.display());
+        }
+
+        Ok(())
+    }
+
+    fn verify_targets(&self, targets: &[(PathBuf, String)]) -> eyre::Result<()> {
+        let mut stale = Vec::new();
+        for (path, expected) in targets {
+            let actual = std::fs::read_to_string(path).unwrap_or_default();
+            if &actual != expected {
+                stale.push(path.display().to_string());
+            }
+        }
+
+        if stale.is_empty() {
+            Ok(())
+        } else {
+            eyre::bail!("generated files are stale, run `cargo xtask codegen`: {stale:?}");
+        }
+    }
+}
+
+fn render_mappings_rs() -> String {
+    let mut out = String::new();
+    out.push_str("// @generated by `cargo xtask codegen`. Do not edit by hand.\n\n");
+    out.push_str("pub struct Mappings;\n\n");
+    out.push_str("impl Mappings {\n");
+    out.push_str("    #[must_use]\n");
+    out.push_str("    pub fn schema_field(&self, cli_type: &str) -> Option<&'static str> {\n");
+    out.push_str("        match cli_type {\n");
+    for ft in FIELD_TYPES {
+        let mut patterns = vec![format!("\"{}\"", ft.cli_type)];
+        patterns.extend(ft.aliases.iter().map(|a| format!("\"{a}\"")));
+        out.push_str(&format!(
+            "            {} => Some(\"{}\"),\n",
+            patterns.join(" | "),
+            ft.schema_type
+        ));
+    }
+    out.push_str("            _ => None,\n");
+    out.push_str("        }\n");
+    out.push_str("    }\n\n");
+    out.push_str("    #[must_use]\n");
+    out.push_str("    pub fn schema_fields(&self) -> Vec<&'static str> {\n");
+    out.push_str("        vec![\n");
+    for ft in FIELD_TYPES {
+        out.push_str(&format!("            \"{}\",\n", ft.cli_type));
+    }
+    out.push_str("        ]\n");
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
+    out.push_str("pub static MAPPINGS: Mappings = Mappings;\n");
+    out
+}
+
+fn render_field_types_md() -> String {
+    let mut out = String::new();
+    out.push_str("<!-- @generated by `cargo xtask codegen`. Do not edit by hand. -->\n\n");
+    out.push_str("# Supported field types\n\n");
+    out.push_str("| CLI type | SeaORM schema type | Rust type | Aliases |\n");
+    out.push_str("|---|---|---|---|\n");
+    for ft in FIELD_TYPES {
+        let aliases = if ft.aliases.is_empty() {
+            "-".to_string()
+        } else {
+            ft.aliases
+                .iter()
+                .map(|a| format!("`{a}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        out.push_str(&format!(
+            "| `{}` | `{}` | `{}` | {aliases} |\n",
+            ft.cli_type, ft.schema_type, ft.rust_type
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_mappings_rs;
+
+    #[test]
+    fn test_render_mappings_rs_is_deterministic() {
+        assert_eq!(render_mappings_rs(), render_mappings_rs());
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
+
+use cargo_metadata::MetadataCommand;
+use flate2::{write::GzEncoder, Compression};
+
+/// Packages every starter template under `base_dir/starters` into a
+/// `loco-<starter>-<version>.tar.gz` under `out_dir`, each containing the
+/// template files plus the workspace's `README.md` and `LICENSE`.
+pub struct Dist {
+    pub base_dir: PathBuf,
+    pub out_dir: PathBuf,
+    pub strip: bool,
+}
+
+impl Dist {
+    pub fn run(&self) -> eyre::Result<Vec<PathBuf>> {
+        let version = self.workspace_version()?;
+        std::fs::create_dir_all(&self.out_dir)?;
+
+        let starters_dir = self.base_dir.join("starters");
+        let mut archives = Vec::new();
+
+        for entry in std::fs::read_dir(&starters_dir)? {
+            let entry = entry?;
+            if !entry.path().is_dir() {
+                continue;
+            }
+            let starter = entry.file_name().to_string_lossy().to_string();
+            let archive = self.package_starter(&starter, &entry.path(), &version)?;
+            println!("packaged {starter}: {}", archive.display());
+            archives.push(archive);
+        }
+
+        if self.strip {
+            self.strip_binaries()?;
+        }
+
+        Ok(archives)
+    }
+
+    fn workspace_version(&self) -> eyre::Result<String> {
+        let meta = MetadataCommand::new()
+            .manifest_path(self.base_dir.join("Cargo.toml"))
+            .current_dir(&self.base_dir)
+            .exec()?;
+        let root = meta
+            .root_package()
+            .ok_or_else(|| eyre::eyre!("could not resolve the workspace root package"))?;
+        Ok(root.version.to_string())
+    }
+
+    fn package_starter(
+        &self,
+        starter: &str,
+        starter_dir: &Path,
+        version: &str,
+    ) -> eyre::Result<PathBuf> {
+        let archive_path = self.out_dir.join(format!("loco-{starter}-{version}.tar.gz"));
+        let tar_gz = File::create(&archive_path)?;
+        let enc = GzEncoder::new(tar_gz, Compression::default());
+        let mut tar = tar::Builder::new(enc);
+
+        tar.append_dir_all(starter, starter_dir)?;
+        for extra in ["README.md", "LICENSE"] {
+            let path = self.base_dir.join(extra);
+            if path.is_file() {
+                tar.append_path_with_name(&path, extra)?;
+            }
+        }
+
+        tar.finish()?;
+        Ok(archive_path)
+    }
+
+    fn strip_binaries(&self) -> eyre::Result<()> {
+        let bin = self.base_dir.join("target/release/loco-tool");
+        if bin.is_file() {
+            duct::cmd!("strip", &bin).run()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use std::path::{Path, PathBuf};
+
+use crazy_train::Randomizer;
+use duct::cmd;
+
+/// Drives `loco new` with a randomized set of flags, to shake out generator
+/// bugs that only show up for uncommon combinations (db/bg/assets/etc).
+pub struct Runner {
+    randomizer: Randomizer,
+    temp_dir: PathBuf,
+}
+
+#[must_use]
+pub fn run(randomizer: Randomizer, temp_dir: &Path) -> Runner {
+    Runner {
+        randomizer,
+        temp_dir: temp_dir.to_path_buf(),
+    }
+}
+
+impl Runner {
+    pub fn run(mut self) -> eyre::Result<()> {
+        std::fs::create_dir_all(&self.temp_dir)?;
+
+        let args = self.randomizer.project_new_args();
+        cmd("loco", &args)
+            .dir(&self.temp_dir)
+            .stderr_to_stdout()
+            .run()?;
+
+        Ok(())
+    }
+}
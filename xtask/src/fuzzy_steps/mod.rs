@@ -0,0 +1,2 @@
+pub mod generate_project;
+pub mod scaffold;
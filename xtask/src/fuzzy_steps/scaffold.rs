@@ -0,0 +1,163 @@
+use std::path::{Path, PathBuf};
+
+use crazy_train::Randomizer;
+use duct::cmd;
+
+/// Drives `loco generate scaffold` with a randomized model name and field
+/// list, to shake out generator bugs across unusual field/type
+/// combinations.
+pub struct Runner {
+    randomizer: Randomizer,
+    temp_dir: PathBuf,
+}
+
+#[must_use]
+pub fn run(randomizer: Randomizer, temp_dir: &Path) -> Runner {
+    Runner {
+        randomizer,
+        temp_dir: temp_dir.to_path_buf(),
+    }
+}
+
+impl Runner {
+    pub fn run(mut self) -> eyre::Result<()> {
+        std::fs::create_dir_all(&self.temp_dir)?;
+
+        let input = ScaffoldInput {
+            model_name: self.randomizer.model_name(),
+            fields: self.randomizer.scaffold_fields(),
+        };
+
+        if let Err(err) = run_scaffold(&input, &self.temp_dir.join("original")) {
+            let seed = self.randomizer.seed();
+            eprintln!("scaffold failed for seed {seed}: {err}");
+
+            let minimal = shrink(&input, &self.temp_dir);
+            eprintln!(
+                "minimal failing input (seed {seed}): model `{}`, fields {:?} (left at {})",
+                minimal.model_name,
+                minimal.fields,
+                self.temp_dir.display()
+            );
+
+            eyre::bail!("scaffold failed for seed {seed}: {err}");
+        }
+
+        Ok(())
+    }
+}
+
+/// The generated scaffold input, captured as a structured value so it can
+/// be minimized by [`shrink`] when a run fails.
+#[derive(Debug, Clone)]
+pub struct ScaffoldInput {
+    pub model_name: String,
+    pub fields: Vec<(String, String)>,
+}
+
+/// Types ordered from richest to simplest; shrinking walks a field's type
+/// down this list one step at a time until the failure stops reproducing.
+const TYPE_SIMPLIFICATION_ORDER: &[&str] =
+    &["decimal(10,2)", "text", "integer", "bool", "string"];
+
+/// Bounds the shrink loop so a pathological input (or a bug that makes
+/// every reduction still fail) can't run forever.
+const MAX_SHRINK_ITERATIONS: usize = 200;
+
+fn run_scaffold(input: &ScaffoldInput, dir: &Path) -> eyre::Result<()> {
+    let mut args = vec![
+        "generate".to_string(),
+        "scaffold".to_string(),
+        input.model_name.clone(),
+    ];
+    args.extend(input.fields.iter().map(|(name, ty)| format!("{name}:{ty}")));
+
+    std::fs::create_dir_all(dir)?;
+    cmd("loco", &args).dir(dir).stderr_to_stdout().run()?;
+    Ok(())
+}
+
+/// Re-runs `input` in a brand new subdirectory of `base_dir` (numbered by
+/// `attempt`) rather than the original's `temp_dir`, so a candidate's files
+/// from a previous attempt can't make this one spuriously pass (`skip_exists`)
+/// or fail (duplicate-file error).
+fn still_fails(input: &ScaffoldInput, base_dir: &Path, attempt: usize) -> bool {
+    let dir = base_dir.join(format!("attempt-{attempt}"));
+    run_scaffold(input, &dir).is_err()
+}
+
+/// Iteratively minimize a failing scaffold input: drop one field at a time,
+/// then simplify remaining field types toward the simplest mapping,
+/// re-running the scaffold after each reduction and keeping it only if the
+/// failure still reproduces. Stops once a full pass makes no progress, or
+/// after [`MAX_SHRINK_ITERATIONS`] re-runs, whichever comes first.
+fn shrink(input: &ScaffoldInput, base_dir: &Path) -> ScaffoldInput {
+    let mut current = input.clone();
+    let mut iterations = 0;
+
+    loop {
+        if iterations >= MAX_SHRINK_ITERATIONS {
+            break;
+        }
+        let mut reduced = false;
+
+        let mut i = 0;
+        while i < current.fields.len() && iterations < MAX_SHRINK_ITERATIONS {
+            let mut candidate = current.clone();
+            candidate.fields.remove(i);
+            iterations += 1;
+
+            if still_fails(&candidate, base_dir, iterations) {
+                current = candidate;
+                reduced = true;
+                // a field shifted into slot `i`; re-check it before advancing
+            } else {
+                i += 1;
+            }
+        }
+
+        for i in 0..current.fields.len() {
+            if iterations >= MAX_SHRINK_ITERATIONS {
+                break;
+            }
+            let Some(simpler) = simpler_type(&current.fields[i].1) else {
+                continue;
+            };
+            let mut candidate = current.clone();
+            candidate.fields[i].1 = simpler.to_string();
+            iterations += 1;
+
+            if still_fails(&candidate, base_dir, iterations) {
+                current = candidate;
+                reduced = true;
+            }
+        }
+
+        if !reduced {
+            break;
+        }
+    }
+
+    current
+}
+
+/// The next-simpler type after `ty` in [`TYPE_SIMPLIFICATION_ORDER`], or
+/// `None` if `ty` is already at (or isn't found in) the simplification
+/// chain.
+fn simpler_type(ty: &str) -> Option<&'static str> {
+    let idx = TYPE_SIMPLIFICATION_ORDER.iter().position(|&t| t == ty)?;
+    TYPE_SIMPLIFICATION_ORDER.get(idx + 1).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simpler_type;
+
+    #[test]
+    fn test_simpler_type_walks_the_chain_to_the_end() {
+        assert_eq!(simpler_type("decimal(10,2)"), Some("text"));
+        assert_eq!(simpler_type("bool"), Some("string"));
+        assert_eq!(simpler_type("string"), None);
+        assert_eq!(simpler_type("not_in_the_chain"), None);
+    }
+}
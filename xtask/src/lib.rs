@@ -0,0 +1,40 @@
+pub mod bump_version;
+pub mod ci;
+pub mod codegen;
+pub mod dist;
+pub mod fuzzy_steps;
+pub mod out;
+pub mod prompt;
+pub mod publish;
+
+/// The outcome of an `xtask` subcommand, carried out to `main` so it can set
+/// the process exit code without every command having to do it itself.
+pub struct CmdExit {
+    pub code: i32,
+    pub message: Option<String>,
+}
+
+impl CmdExit {
+    #[must_use]
+    pub fn ok() -> Self {
+        Self {
+            code: 0,
+            message: None,
+        }
+    }
+
+    #[must_use]
+    pub fn error_with_message(message: &str) -> Self {
+        Self {
+            code: 1,
+            message: Some(message.to_string()),
+        }
+    }
+
+    pub fn exit(self) {
+        if let Some(message) = self.message {
+            eprintln!("{message}");
+        }
+        std::process::exit(self.code);
+    }
+}
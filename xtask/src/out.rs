@@ -0,0 +1,14 @@
+use crate::ci::CiResult;
+
+/// Render `cargo xtask test` results as a human-readable summary table.
+#[must_use]
+pub fn print_ci_results(results: &[CiResult]) -> String {
+    results
+        .iter()
+        .map(|r| {
+            let status = if r.success { "OK" } else { "FAILED" };
+            format!("{:<30} {status}", r.resource)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
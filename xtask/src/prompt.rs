@@ -0,0 +1,12 @@
+use std::io::{self, Write};
+
+/// Ask the user to confirm `message` on stdin, defaulting to `false`.
+pub fn confirmation(message: &str) -> eyre::Result<bool> {
+    print!("{message} [y/N]: ");
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(matches!(input.trim(), "y" | "Y" | "yes" | "YES"))
+}
@@ -0,0 +1,195 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    time::Duration,
+};
+
+use cargo_metadata::{MetadataCommand, Package, PackageId};
+
+/// A single crate's place in the publish order, plus whatever would make it
+/// a no-op to publish again.
+#[derive(Debug)]
+pub struct PlannedCrate {
+    pub name: String,
+    pub version: String,
+    pub already_published: bool,
+}
+
+impl PlannedCrate {
+    #[must_use]
+    pub fn should_skip(&self) -> bool {
+        self.already_published
+    }
+}
+
+/// Computes a dependency-ordered publish plan across the workspace and
+/// starters, then (unless `dry_run`) runs `cargo publish` per crate.
+pub struct Publish {
+    pub base_dir: PathBuf,
+    pub dry_run: bool,
+}
+
+impl Publish {
+    pub fn run(&self) -> eyre::Result<()> {
+        let plan = self.plan()?;
+
+        if self.dry_run {
+            for crate_plan in &plan {
+                let status = if crate_plan.already_published {
+                    "skip (already on crates.io)"
+                } else {
+                    "publish"
+                };
+                println!("{} {} - {status}", crate_plan.name, crate_plan.version);
+            }
+            return Ok(());
+        }
+
+        for crate_plan in plan.iter().filter(|p| !p.should_skip()) {
+            self.publish_with_retry(crate_plan)?;
+        }
+
+        Ok(())
+    }
+
+    /// Build the internal dependency graph, topologically sort it so
+    /// dependents always come after their dependencies, and drop any crate
+    /// that opts out of publishing (`publish = false` or
+    /// `package.metadata.loco.stability = "experimental"`).
+    pub fn plan(&self) -> eyre::Result<Vec<PlannedCrate>> {
+        let meta = MetadataCommand::new()
+            .manifest_path(self.base_dir.join("Cargo.toml"))
+            .current_dir(&self.base_dir)
+            .exec()?;
+
+        let workspace_members: HashSet<&PackageId> = meta.workspace_members.iter().collect();
+        let packages: HashMap<&PackageId, &Package> =
+            meta.packages.iter().map(|p| (&p.id, p)).collect();
+
+        let mut graph: HashMap<&PackageId, Vec<&PackageId>> = HashMap::new();
+        for id in &workspace_members {
+            let pkg = packages[id];
+            let deps = pkg
+                .dependencies
+                .iter()
+                .filter_map(|dep| {
+                    packages
+                        .values()
+                        .find(|p| p.name == dep.name && workspace_members.contains(&p.id))
+                })
+                .map(|p| &p.id)
+                .collect();
+            graph.insert(id, deps);
+        }
+
+        let order = topo_sort(&graph)?;
+
+        Ok(order
+            .into_iter()
+            .map(|id| packages[id])
+            .filter(|pkg| is_publishable(pkg))
+            .map(|pkg| PlannedCrate {
+                name: pkg.name.clone(),
+                version: pkg.version.to_string(),
+                already_published: crates_io_has_version(&pkg.name, &pkg.version.to_string()),
+            })
+            .collect())
+    }
+
+    fn publish_with_retry(&self, crate_plan: &PlannedCrate) -> eyre::Result<()> {
+        const MAX_ATTEMPTS: u32 = 5;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            let result = duct::cmd!("cargo", "publish", "-p", &crate_plan.name)
+                .dir(&self.base_dir)
+                .run();
+
+            match result {
+                Ok(_) => return Ok(()),
+                Err(err) if attempt < MAX_ATTEMPTS => {
+                    eprintln!(
+                        "publish of {} failed (attempt {attempt}/{MAX_ATTEMPTS}): {err}; retrying after index propagation",
+                        crate_plan.name
+                    );
+                    std::thread::sleep(Duration::from_secs(10));
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+}
+
+/// `publish = false` in `[package]`, or `package.metadata.loco.stability =
+/// "experimental"`, both opt a crate out of the publish plan.
+fn is_publishable(pkg: &Package) -> bool {
+    if matches!(&pkg.publish, Some(allowed) if allowed.is_empty()) {
+        return false;
+    }
+
+    let stability = pkg
+        .metadata
+        .get("loco")
+        .and_then(|loco| loco.get("stability"))
+        .and_then(|v| v.as_str());
+
+    stability != Some("experimental")
+}
+
+fn crates_io_has_version(name: &str, version: &str) -> bool {
+    let url = format!("https://crates.io/api/v1/crates/{name}/{version}");
+    ureq::get(&url)
+        .call()
+        .map(|resp| resp.status() == 200)
+        .unwrap_or(false)
+}
+
+/// Kahn's algorithm over `depends_on`, publishing a crate only once every
+/// crate it depends on has already been placed. Returns an error naming the
+/// crates involved in a dependency cycle instead of looping forever.
+fn topo_sort<'a>(
+    depends_on: &HashMap<&'a PackageId, Vec<&'a PackageId>>,
+) -> eyre::Result<Vec<&'a PackageId>> {
+    let mut dependents: HashMap<&PackageId, Vec<&PackageId>> = HashMap::new();
+    let mut remaining_deps: HashMap<&PackageId, usize> = HashMap::new();
+
+    for (&id, deps) in depends_on {
+        remaining_deps.insert(id, deps.len());
+        for &dep in deps {
+            dependents.entry(dep).or_default().push(id);
+        }
+    }
+
+    let mut queue: Vec<&PackageId> = remaining_deps
+        .iter()
+        .filter(|(_, &count)| count == 0)
+        .map(|(&id, _)| id)
+        .collect();
+    queue.sort();
+
+    let mut order = Vec::new();
+    while let Some(id) = queue.pop() {
+        order.push(id);
+        for &dependent in dependents.get(id).into_iter().flatten() {
+            let count = remaining_deps
+                .get_mut(dependent)
+                .expect("dependent tracked in remaining_deps");
+            *count -= 1;
+            if *count == 0 {
+                queue.push(dependent);
+            }
+        }
+    }
+
+    if order.len() != depends_on.len() {
+        let stuck: Vec<_> = depends_on
+            .keys()
+            .filter(|id| !order.contains(id))
+            .map(|id| id.repr.clone())
+            .collect();
+        eyre::bail!("dependency cycle detected among: {stuck:?}");
+    }
+
+    Ok(order)
+}